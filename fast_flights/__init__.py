@@ -1,17 +1,54 @@
 from .core import get_flights
-from .flights_impl import Airport, TFSData, FlightData, Passengers
+from .flights_impl import (
+    Airport,
+    TFSData,
+    FlightData,
+    Passengers,
+    schema_version,
+    summarize_tfs,
+    tfs_equal,
+    tfs_schema,
+    write_jsonl,
+    read_jsonl,
+    fan_out,
+    SCHEMA_VERSION,
+    MAX_PASSENGERS,
+    MAX_INFANTS_ON_LAP_PER_ADULT,
+    MAX_INFANTS_PER_ADULT,
+)
 from .schema import Result, Flight
-from .filter import create_filter
+from .filter import (
+    create_filter,
+    create_filter_checked,
+    simple_one_way,
+    simple_round_trip,
+    simple_multi_city,
+)
 from .search import search_airport
 
 __all__ = [
     "Airport",
     "TFSData",
     "create_filter",
+    "create_filter_checked",
+    "simple_one_way",
+    "simple_round_trip",
+    "simple_multi_city",
     "FlightData",
     "Passengers",
     "get_flights",
     "Result",
     "Flight",
     "search_airport",
+    "schema_version",
+    "summarize_tfs",
+    "tfs_equal",
+    "tfs_schema",
+    "write_jsonl",
+    "read_jsonl",
+    "fan_out",
+    "SCHEMA_VERSION",
+    "MAX_PASSENGERS",
+    "MAX_INFANTS_ON_LAP_PER_ADULT",
+    "MAX_INFANTS_PER_ADULT",
 ]
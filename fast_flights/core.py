@@ -2,25 +2,15 @@ from typing import Any, Optional
 import requests
 from selectolax.lexbor import LexborHTMLParser, LexborNode
 
-from .flights_impl import TFSData
+from .flights_impl import FLIGHTS_HEADERS, FLIGHTS_PARAMS, FLIGHTS_URL, TFSData
 from .schema import Flight, Result
 
-ua = (
-    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) "
-    "AppleWebKit/537.36 (KHTML, like Gecko) "
-    "Chrome/123.0.0.0 Safari/537.36 OPR/109.0.0.0"
-)
-
 
 def request_flights(tfs: TFSData, **kwargs: Any) -> requests.Response:
     r = requests.get(
-        "https://www.google.com/travel/flights",
-        params={
-            "tfs": tfs.as_b64(),
-            "hl": "en",
-            "tfu": "EgQIABABIgA",  # show all flights and prices condition
-        },
-        headers={"user-agent": ua, "accept-language": "en"},
+        FLIGHTS_URL,
+        params={"tfs": tfs.as_b64(), **FLIGHTS_PARAMS},
+        headers=FLIGHTS_HEADERS,
         **kwargs
     )
     r.raise_for_status()
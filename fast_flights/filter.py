@@ -1,22 +1,202 @@
-from typing import Literal, List
-from .flights_impl import FlightData, Passengers, TFSData
+import warnings
+from typing import Dict, Literal, List, Optional, Tuple, Union
+from .flights_impl import Airport, FlightData, Passengers, TFSData
+
+
+def _duplicate_segments(flight_data: List[FlightData]) -> List[str]:
+    return [
+        f"Duplicate segment in flight_data: {fd!r}"
+        for i, fd in enumerate(flight_data)
+        if fd in flight_data[:i]
+    ]
+
+
+def _premium_cabin(seat: str) -> List[str]:
+    if seat in ("premium-economy", "business", "first"):
+        return [f"{seat!r} seats may not be offered on short routes"]
+
+    return []
 
 
 def create_filter(
     *,
     flight_data: List[FlightData],
-    trip: Literal["round-trip", "one-way", "multi-city"],
-    passengers: Passengers,
-    seat: Literal["economy", "premium-economy", "business", "first"],
+    trip: Optional[Literal["round-trip", "one-way", "multi-city"]] = None,
+    passengers: Union[Passengers, Dict[str, int]],
+    seat: Literal["economy", "premium-economy", "business", "first", "any"],
+    warn_duplicates: bool = False,
+    check_chronological: bool = False,
+    warn_premium_cabin: bool = False,
+    allow_trip_mismatch: bool = False,
 ) -> TFSData:
     """Create a filter. (``?tfs=``)
 
     Args:
         flight_data (list[FlightData]): Flight data as a list.
-        trip ("one-way" | "round-trip" | "multi-city"): Trip type.
-        passengers (Passengers): Passengers.
-        seat ("economy" | "premium-economy" | "business" | "first"): Seat.
+        trip ("one-way" | "round-trip" | "multi-city", optional): Trip type.
+            Inferred from ``flight_data`` if omitted.
+        passengers (Passengers | dict[str, int]): Passengers, or the keyword
+            arguments of ``Passengers`` as a dict, e.g. ``{"adults": 2}``.
+        seat ("economy" | "premium-economy" | "business" | "first" | "any"): Seat.
+            ``"any"`` omits the seat field so no cabin is forced.
+        warn_duplicates (bool, optional): Emit a warning when the same segment
+            appears more than once in ``flight_data``. Defaults to ``False``.
+        check_chronological (bool, optional): Raise ``ValueError`` when a segment
+            departs before the one preceding it. Defaults to ``False``.
+        warn_premium_cabin (bool, optional): Emit a warning when ``seat`` is
+            above economy, since premium cabins often don't exist on short
+            routes and yield no results. Advisory only. Defaults to ``False``.
+        allow_trip_mismatch (bool, optional): Don't check that ``trip`` matches
+            the segments (one-way: one segment, round-trip: two mirrored
+            segments, multi-city: two or more), here or on copies of the filter
+            (e.g. ``TFSData.with_date``). Defaults to ``False``.
     """
+    if isinstance(passengers, dict):
+        passengers = Passengers(**passengers)
+
+    if warn_duplicates:
+        for advisory in _duplicate_segments(flight_data):
+            warnings.warn(advisory, stacklevel=2)
+
+    if warn_premium_cabin:
+        for advisory in _premium_cabin(seat):
+            warnings.warn(advisory, stacklevel=2)
+
+    if check_chronological:
+        for prev, fd in zip(flight_data, flight_data[1:]):
+            if fd.date < prev.date:
+                raise ValueError(
+                    f"Segments are not in chronological order: {fd!r} departs "
+                    f"before {prev!r}"
+                )
+
     return TFSData.from_interface(
-        flight_data=flight_data, trip=trip, passengers=passengers, seat=seat
+        flight_data=flight_data,
+        trip=trip,
+        passengers=passengers,
+        seat=seat,
+        allow_trip_mismatch=allow_trip_mismatch,
+    )
+
+
+def create_filter_checked(
+    *,
+    flight_data: List[FlightData],
+    trip: Optional[Literal["round-trip", "one-way", "multi-city"]] = None,
+    passengers: Union[Passengers, Dict[str, int]],
+    seat: Literal["economy", "premium-economy", "business", "first", "any"],
+    check_chronological: bool = False,
+    allow_trip_mismatch: bool = False,
+) -> Tuple[TFSData, List[str]]:
+    """Create a filter, along with advisories about it (e.g. for form UIs).
+
+    Takes the same arguments as ``create_filter``. Instead of emitting warnings,
+    every advisory check runs and its messages are returned: duplicate segments
+    and premium cabins. Errors are still raised.
+
+    Returns:
+        tuple[TFSData, list[str]]: The filter and the advisories.
+    """
+    tfs = create_filter(
+        flight_data=flight_data,
+        trip=trip,
+        passengers=passengers,
+        seat=seat,
+        check_chronological=check_chronological,
+        allow_trip_mismatch=allow_trip_mismatch,
+    )
+    return tfs, _duplicate_segments(flight_data) + _premium_cabin(seat)
+
+
+def simple_one_way(
+    *,
+    from_airport: Union[Airport, str],
+    to_airport: Union[Airport, str],
+    date: str,
+    adults: int = 1,
+    seat: Literal["economy", "premium-economy", "business", "first", "any"] = "economy",
+) -> TFSData:
+    """Create a filter for a one-way trip with adult passengers only.
+
+    Args:
+        from_airport (Airport | str): Departure (airport). Where from?
+        to_airport (Airport | str): Arrival (airport). Where to?
+        date (str): Date of departure.
+        adults (int, optional): Number of adults. Defaults to 1.
+        seat ("economy" | "premium-economy" | "business" | "first" | "any", optional):
+            Seat. Defaults to ``"economy"``.
+    """
+    return create_filter(
+        flight_data=[
+            FlightData(date=date, from_airport=from_airport, to_airport=to_airport)
+        ],
+        trip="one-way",
+        passengers=Passengers(adults=adults),
+        seat=seat,
+    )
+
+
+def simple_round_trip(
+    *,
+    from_airport: Union[Airport, str],
+    to_airport: Union[Airport, str],
+    depart_date: str,
+    return_date: str,
+    adults: int = 1,
+    seat: Literal["economy", "premium-economy", "business", "first", "any"] = "economy",
+) -> TFSData:
+    """Create a filter for a round trip with adult passengers only.
+
+    The return segment is built by mirroring the outbound one.
+
+    Args:
+        from_airport (Airport | str): Departure (airport). Where from?
+        to_airport (Airport | str): Arrival (airport). Where to?
+        depart_date (str): Date of departure.
+        return_date (str): Date of return. Same day as departure is allowed.
+        adults (int, optional): Number of adults. Defaults to 1.
+        seat ("economy" | "premium-economy" | "business" | "first" | "any", optional):
+            Seat. Defaults to ``"economy"``.
+
+    Raises:
+        ValueError: ``return_date`` is before ``depart_date``.
+    """
+    return create_filter(
+        flight_data=[
+            FlightData(
+                date=depart_date, from_airport=from_airport, to_airport=to_airport
+            ),
+            FlightData(
+                date=return_date, from_airport=to_airport, to_airport=from_airport
+            ),
+        ],
+        trip="round-trip",
+        passengers=Passengers(adults=adults),
+        seat=seat,
+    )
+
+
+def simple_multi_city(
+    *,
+    segments: List[Tuple[Union[Airport, str], Union[Airport, str], str]],
+    adults: int = 1,
+    seat: Literal["economy", "premium-economy", "business", "first", "any"] = "economy",
+) -> TFSData:
+    """Create a filter for a multi-city trip with adult passengers only.
+
+    Args:
+        segments (list[tuple[Airport | str, Airport | str, str]]): The legs in
+            order, as ``(from_airport, to_airport, date)``.
+        adults (int, optional): Number of adults. Defaults to 1.
+        seat ("economy" | "premium-economy" | "business" | "first" | "any", optional):
+            Seat. Defaults to ``"economy"``.
+    """
+    return create_filter(
+        flight_data=[
+            FlightData(date=date, from_airport=from_airport, to_airport=to_airport)
+            for from_airport, to_airport, date in segments
+        ],
+        trip="multi-city",
+        passengers=Passengers(adults=adults),
+        seat=seat,
     )
@@ -1,7 +1,22 @@
 """Typed implementation of flights_pb2.py"""
 
 import base64
-from typing import Any, List, TYPE_CHECKING, Literal, Union
+import json
+import zlib
+from typing import (
+    Any,
+    Dict,
+    Iterable,
+    Iterator,
+    List,
+    TYPE_CHECKING,
+    Literal,
+    Optional,
+    Union,
+)
+from urllib.parse import parse_qs, urlencode, urlparse
+
+from google.protobuf.message import DecodeError
 
 from . import flights_pb2 as PB
 from ._generated_enum import Airport
@@ -9,14 +24,67 @@ from ._generated_enum import Airport
 if TYPE_CHECKING:
     PB: Any
 
+# Revision of flights.proto the encoder targets. Bump when fields change.
+SCHEMA_VERSION = "1.0"
+
+
+# Trip types as passed to `create_filter`
+TRIP_TYPES = {
+    "round-trip": PB.Trip.ROUND_TRIP,
+    "one-way": PB.Trip.ONE_WAY,
+    "multi-city": PB.Trip.MULTI_CITY,
+}
+
+# Alphabet of `TFSData.short_code`
+BASE62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+
+# Google's limits on a single search
+MAX_PASSENGERS = 9  # seated passengers; infants on lap don't count
+MAX_INFANTS_ON_LAP_PER_ADULT = 1
+MAX_INFANTS_PER_ADULT = 2  # on lap and in seat combined
+
+# What `core.request_flights` sends along with ``?tfs=``
+FLIGHTS_URL = "https://www.google.com/travel/flights"
+FLIGHTS_PARAMS = {
+    "hl": "en",
+    "tfu": "EgQIABABIgA",  # show all flights and prices condition
+}
+FLIGHTS_HEADERS = {
+    "user-agent": (
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) "
+        "AppleWebKit/537.36 (KHTML, like Gecko) "
+        "Chrome/123.0.0.0 Safari/537.36 OPR/109.0.0.0"
+    ),
+    "accept-language": "en",
+}
+
+
+def schema_version() -> str:
+    """Version of the ``flights.proto`` schema used to encode ``?tfs=``."""
+    return SCHEMA_VERSION
+
+
+def _normalize_airport(airport: Union[Airport, str]) -> str:
+    code = airport.value if isinstance(airport, Airport) else airport
+
+    # Only IATA codes; city IDs like "/m/02_286" are case-sensitive
+    if len(code) == 3 and code.isalpha():
+        return code.upper()
+
+    return code
+
 
 class FlightData:
     """Represents flight data.
 
+    The cabin class applies to the whole search (``seat`` in ``create_filter``);
+    Google's ``?tfs=`` format has no per-segment cabin.
+
     Args:
         date (str): Date.
-        from_airport (str): Departure (airport). Where from?
-        to_airport (str): Arrival (airport). Where to?
+        from_airport (str): Departure (airport). Where from? IATA codes are
+            uppercased.
+        to_airport (str): Arrival (airport). Where to? IATA codes are uppercased.
     """
 
     __slots__ = ("date", "from_airport", "to_airport")
@@ -32,12 +100,20 @@ class FlightData:
         to_airport: Union[Airport, str],
     ):
         self.date = date
-        self.from_airport = (
-            from_airport.value if isinstance(from_airport, Airport) else from_airport
-        )
-        self.to_airport = (
-            to_airport.value if isinstance(to_airport, Airport) else to_airport
-        )
+        self.from_airport = _normalize_airport(from_airport)
+        self.to_airport = _normalize_airport(to_airport)
+
+    @staticmethod
+    def from_pb(data: PB.FlightData) -> "FlightData":  # type: ignore
+        """A segment from decoded data, as-is.
+
+        Airports are not normalized, so that re-encoding gives the same bytes.
+        """
+        fd = FlightData.__new__(FlightData)
+        fd.date = data.date
+        fd.from_airport = data.from_flight.airport
+        fd.to_airport = data.to_flight.airport
+        return fd
 
     def attach(self, info: PB.Info) -> None:  # type: ignore
         data = info.data.add()
@@ -45,6 +121,31 @@ class FlightData:
         data.from_flight.airport = self.from_airport
         data.to_flight.airport = self.to_airport
 
+    def as_dict(self) -> Dict[str, str]:
+        """The segment as ``{"date": ..., "from": ..., "to": ...}``."""
+        return {"date": self.date, "from": self.from_airport, "to": self.to_airport}
+
+    def is_mirror_of(self, other: "FlightData") -> bool:
+        """Whether this segment flies ``other`` backwards, on the same day or later."""
+        return (
+            self.from_airport == other.to_airport
+            and self.to_airport == other.from_airport
+            and self.date >= other.date
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, FlightData):
+            return NotImplemented
+
+        return (self.date, self.from_airport, self.to_airport) == (
+            other.date,
+            other.from_airport,
+            other.to_airport,
+        )
+
+    def __hash__(self) -> int:
+        return hash((self.date, self.from_airport, self.to_airport))
+
     def __repr__(self) -> str:
         return (
             f"FlightData(date={self.date!r}, "
@@ -62,13 +163,22 @@ class Passengers:
         infants_in_seat: int = 0,
         infants_on_lap: int = 0,
     ):
+        # Infants on lap don't take a seat
         assert (
-            sum((adults, children, infants_in_seat, infants_on_lap)) <= 9
-        ), "Too many passengers (> 9)"
+            sum((adults, children, infants_in_seat)) <= MAX_PASSENGERS
+        ), f"Too many seated passengers (> {MAX_PASSENGERS})"
         assert (
-            infants_on_lap <= adults
+            infants_on_lap <= adults * MAX_INFANTS_ON_LAP_PER_ADULT
         ), "You must have at least one adult per infant on lap"
+        assert (
+            infants_in_seat + infants_on_lap <= adults * MAX_INFANTS_PER_ADULT
+        ), f"Too many infants (> {MAX_INFANTS_PER_ADULT} per adult)"
+
+        self._fill(adults, children, infants_in_seat, infants_on_lap)
 
+    def _fill(
+        self, adults: int, children: int, infants_in_seat: int, infants_on_lap: int
+    ) -> None:
         self.pb = []
         self.pb += [PB.Passenger.ADULT for _ in range(adults)]
         self.pb += [PB.Passenger.CHILD for _ in range(children)]
@@ -77,6 +187,24 @@ class Passengers:
 
         self._data = (adults, children, infants_in_seat, infants_on_lap)
 
+    @staticmethod
+    def from_pb(passengers: List[PB.Passenger]) -> "Passengers":  # type: ignore
+        """Passengers from decoded data, as-is.
+
+        The passenger limits are not checked, so that any blob can be decoded
+        and inspected.
+        """
+        passengers = list(passengers)
+
+        p = Passengers.__new__(Passengers)
+        p._fill(
+            passengers.count(PB.Passenger.ADULT),
+            passengers.count(PB.Passenger.CHILD),
+            passengers.count(PB.Passenger.INFANT_IN_SEAT),
+            passengers.count(PB.Passenger.INFANT_ON_LAP),
+        )
+        return p
+
     def attach(self, info: PB.Info) -> None:  # type: ignore
         for p in self.pb:
             info.passengers.append(p)
@@ -85,6 +213,89 @@ class Passengers:
         return f"Passengers({self._data})"
 
 
+def infer_trip(
+    flight_data: List[FlightData],
+) -> Literal["round-trip", "one-way", "multi-city"]:
+    """Guess the trip type from the shape of the segments.
+
+    One segment is a one-way trip, two mirrored segments (``A -> B``, then
+    ``B -> A`` on the same day or later) are a round trip, anything else is
+    a multi-city trip.
+
+    This is ambiguous for a multi-city trip that happens to return to where
+    it started in two legs; pass ``trip`` explicitly in that case.
+
+    Args:
+        flight_data (list[FlightData]): Flight data as a list.
+    """
+    if len(flight_data) == 1:
+        return "one-way"
+
+    if len(flight_data) == 2 and flight_data[1].is_mirror_of(flight_data[0]):
+        return "round-trip"
+
+    return "multi-city"
+
+
+def check_trip(
+    trip: Literal["round-trip", "one-way", "multi-city"],
+    flight_data: List[FlightData],
+) -> None:
+    """Make sure the trip type matches the shape of the segments.
+
+    A one-way trip has one segment, a round trip has two mirrored segments
+    and a multi-city trip has at least two segments.
+
+    Args:
+        trip ("one-way" | "round-trip" | "multi-city"): Trip type.
+        flight_data (list[FlightData]): Flight data as a list.
+
+    Raises:
+        ValueError: The trip type doesn't match the segments.
+    """
+    if trip == "one-way" and len(flight_data) != 1:
+        raise ValueError(
+            f"A one-way trip has exactly one segment, got {len(flight_data)}; "
+            "use trip='multi-city' for several legs"
+        )
+
+    if trip == "round-trip":
+        if len(flight_data) == 1:
+            raise ValueError(
+                "A round trip needs a return segment: pass the outbound and the "
+                "return FlightData, or use trip='one-way'"
+            )
+
+        if len(flight_data) != 2:
+            raise ValueError(
+                f"A round trip has exactly two segments, got {len(flight_data)}; "
+                "use trip='multi-city' for several legs"
+            )
+
+        outbound, inbound = flight_data
+        if (inbound.from_airport, inbound.to_airport) != (
+            outbound.to_airport,
+            outbound.from_airport,
+        ):
+            raise ValueError(
+                f"Open-jaw trip ({outbound.from_airport} -> {outbound.to_airport}, "
+                f"{inbound.from_airport} -> {inbound.to_airport}) is not a round "
+                "trip; use trip='multi-city'"
+            )
+
+        if not inbound.is_mirror_of(outbound):
+            raise ValueError(
+                f"The return segment departs ({inbound.date}) before the outbound "
+                f"one ({outbound.date})"
+            )
+
+    if trip == "multi-city" and len(flight_data) < 2:
+        raise ValueError(
+            f"A multi-city trip has at least two segments, got {len(flight_data)}; "
+            "use trip='one-way' for a single leg"
+        )
+
+
 class TFSData:
     """``?tfs=`` data. (internal)
 
@@ -98,12 +309,25 @@ class TFSData:
         seat: PB.Seat,  # type: ignore
         trip: PB.Trip,  # type: ignore
         passengers: Passengers,
+        hl: Optional[str] = None,
+        curr: Optional[str] = None,
+        allow_trip_mismatch: bool = False,
     ):
         self.flight_data = flight_data
         self.seat = seat
         self.trip = trip
         self.passengers = passengers
 
+        # Whether copies skip `check_trip`, as this search did
+        self.allow_trip_mismatch = allow_trip_mismatch
+
+        # Language & currency, only known when parsed with `TFSData.from_url`
+        self.hl = hl
+        self.curr = curr
+
+        # The bytes this was decoded from, if any (see `self_consistent`)
+        self._raw: Optional[bytes] = None
+
     def pb(self) -> PB.Info:  # type: ignore
         info = PB.Info()
         info.seat = self.seat
@@ -119,40 +343,655 @@ class TFSData:
     def to_string(self) -> bytes:
         return self.pb().SerializeToString()
 
-    def as_b64(self) -> bytes:
-        return base64.b64encode(self.to_string())
+    def as_b64(
+        self, variant: Literal["standard", "urlsafe", "urlsafe_nopad"] = "standard"
+    ) -> bytes:
+        """Base64-encoded data.
+
+        Args:
+            variant ("standard" | "urlsafe" | "urlsafe_nopad", optional): Base64
+                alphabet and padding. ``"urlsafe_nopad"`` is what the browser puts
+                in URLs. Defaults to ``"standard"``.
+        """
+        if variant == "standard":
+            return base64.b64encode(self.to_string())
+
+        if variant == "urlsafe":
+            return base64.urlsafe_b64encode(self.to_string())
+
+        if variant == "urlsafe_nopad":
+            return base64.urlsafe_b64encode(self.to_string()).rstrip(b"=")
+
+        raise ValueError(f"Unknown base64 variant: {variant!r}")
+
+    @staticmethod
+    def from_pb(info: PB.Info) -> "TFSData":  # type: ignore
+        return TFSData(
+            flight_data=[FlightData.from_pb(data) for data in info.data],
+            seat=info.seat,
+            trip=info.trip,
+            passengers=Passengers.from_pb(info.passengers),
+        )
+
+    @staticmethod
+    def from_string(data: bytes) -> "TFSData":
+        """Decode serialized ``?tfs=`` data. The inverse of ``to_string``."""
+        info = PB.Info()
+        info.ParseFromString(data)
+
+        tfs = TFSData.from_pb(info)
+        tfs._raw = data
+        return tfs
+
+    @staticmethod
+    def from_b64(data: Union[str, bytes]) -> "TFSData":
+        """Decode base64 ``?tfs=`` data. The inverse of ``as_b64``.
+
+        Accepts every ``as_b64`` variant, including the browser's URL-safe,
+        unpadded form.
+
+        Raises:
+            ValueError: The data is not base64.
+        """
+        if isinstance(data, bytes):
+            data = data.decode()
+
+        data = data.replace("-", "+").replace("_", "/")
+        data += "=" * (-len(data) % 4)
+        return TFSData.from_string(base64.b64decode(data, validate=True))
+
+    @staticmethod
+    def from_url(url: str) -> "TFSData":
+        """Parse a Google Flights URL copied from the browser.
+
+        The ``hl`` (language) and ``curr`` (currency) params are kept on the
+        returned ``TFSData`` if present.
+
+        Args:
+            url (str): The URL, e.g. ``https://www.google.com/travel/flights?tfs=...``.
+
+        Raises:
+            ValueError: The URL has no ``tfs`` param, or it could not be decoded.
+        """
+        query = parse_qs(urlparse(url).query)
+        if "tfs" not in query:
+            raise ValueError(f"No tfs param in URL: {url!r}")
+
+        try:
+            tfs = _decode_b64(query["tfs"][0])
+        except ValueError as e:
+            raise ValueError(f"Invalid tfs param in URL: {url!r}") from e
+
+        tfs.hl = query.get("hl", [None])[0]
+        tfs.curr = query.get("curr", [None])[0]
+        return tfs
+
+    def segments(self) -> List[FlightData]:
+        """The segments, with ``date``, ``from_airport`` and ``to_airport``."""
+        return list(self.flight_data)
+
+    def all_airports(self) -> List[str]:
+        """Every airport in the segments, deduplicated, in order of appearance."""
+        airports = []
+        for fd in self.flight_data:
+            airports += [fd.from_airport, fd.to_airport]
+
+        return list(dict.fromkeys(airports))
+
+    def validate_airports_known(self) -> None:
+        """Make sure every airport is a code in the ``Airport`` enum.
+
+        Raises:
+            ValueError: An airport is not in the enum.
+        """
+        known = {airport.value for airport in Airport}
+        for airport in self.all_airports():
+            if airport not in known:
+                raise ValueError(f"Unknown airport: {airport!r}")
+
+    def passenger_counts(self) -> Dict[str, int]:
+        """Number of passengers by type, e.g. ``{"adults": 2, "children": 1, ...}``."""
+        return dict(
+            zip(
+                ("adults", "children", "infants_in_seat", "infants_on_lap"),
+                self.passengers._data,
+            )
+        )
+
+    def is_valid_round_trip(self) -> bool:
+        """Whether this is a round trip of two mirrored segments, returning later."""
+        if self.trip != PB.Trip.ROUND_TRIP or len(self.flight_data) != 2:
+            return False
+
+        outbound, inbound = self.flight_data
+        return inbound.is_mirror_of(outbound) and inbound.date > outbound.date
+
+    def cache_key(self) -> str:
+        """A short, stable key for this search, for caching.
+
+        Equal searches always give the same key, e.g.
+        ``rt:ECO:GRU-CNF:2025-05-10:CNF-GRU:2025-05-17:A1C0S0L0``.
+        """
+        trip = {
+            PB.Trip.ROUND_TRIP: "rt",
+            PB.Trip.ONE_WAY: "ow",
+            PB.Trip.MULTI_CITY: "mc",
+        }.get(self.trip, "xx")
+        seat = {
+            PB.Seat.ECONOMY: "ECO",
+            PB.Seat.PREMIUM_ECONOMY: "PRE",
+            PB.Seat.BUSINESS: "BUS",
+            PB.Seat.FIRST: "FST",
+        }.get(self.seat, "ANY")
+        adults, children, infants_in_seat, infants_on_lap = self.passengers._data
+
+        return ":".join(
+            (
+                trip,
+                seat,
+                *(
+                    f"{fd.from_airport}-{fd.to_airport}:{fd.date}"
+                    for fd in self.flight_data
+                ),
+                f"A{adults}C{children}S{infants_in_seat}L{infants_on_lap}",
+            )
+        )
+
+    def as_dict(self) -> Dict[str, Any]:
+        """The search as JSON-friendly data.
+
+        ``"tfs"`` holds the base64 data, which is what ``read_jsonl`` decodes;
+        the other fields are there for readability.
+        """
+        return {
+            "trip": self._trip_name(),
+            "seat": self._seat_name(),
+            "segments": list(self),
+            "passengers": self.passenger_counts(),
+            "hl": self.hl,
+            "curr": self.curr,
+            "tfs": self.as_b64().decode(),
+        }
+
+    def self_consistent(self) -> bool:
+        """Whether decoding and re-encoding the data gives back the same bytes.
+
+        For a search decoded with ``from_string`` or ``from_b64``, the bytes are
+        the ones it was decoded from, so this catches data that doesn't survive
+        decoding (e.g. reordered passengers).
+        """
+        if self._raw is not None:
+            return self.to_string() == self._raw
+
+        data = self.to_string()
+        return TFSData.from_string(data).to_string() == data
+
+    def curl_command(self, hl: str = "en", curr: Optional[str] = None) -> str:
+        """A ready-to-run ``curl`` command fetching this search, for debugging.
+
+        The params and headers are the ones ``get_flights`` sends.
+
+        Args:
+            hl (str, optional): Language. Defaults to ``"en"``.
+            curr (str, optional): Currency, e.g. ``"USD"``.
+        """
+        params = {"tfs": self.as_b64().decode(), **FLIGHTS_PARAMS, "hl": hl}
+        if curr:
+            params["curr"] = curr
+
+        headers = " ".join(f"-H '{k}: {v}'" for k, v in FLIGHTS_HEADERS.items())
+        return f"curl '{FLIGHTS_URL}?{urlencode(params)}' {headers}"
+
+    def short_code(self) -> str:
+        """An opaque, alphanumeric (base62) code for storing this search.
+
+        The protobuf is deflated first, so round trips and multi-city searches
+        come out well shorter than ``as_b64()``. One-way searches barely
+        compress and come out longer (e.g. 49 characters for ``GRU -> CNF``,
+        against 48).
+        Use ``TFSData.from_short_code`` to get the search back.
+        """
+        z = zlib.compressobj(9, zlib.DEFLATED, -15)  # raw deflate, no header
+        # A single final block, so the first byte is never zero and survives
+        # the round trip through an integer
+        n = int.from_bytes(z.compress(self.to_string()) + z.flush(), "big")
+
+        code = ""
+        while n:
+            n, i = divmod(n, 62)
+            code = BASE62[i] + code
+
+        return code
+
+    @staticmethod
+    def from_short_code(code: str) -> "TFSData":
+        """Decode a code from ``TFSData.short_code``.
+
+        Raises:
+            ValueError: The code is not base62, or not a deflated search.
+        """
+        n = 0
+        for c in code:
+            i = BASE62.find(c)
+            if i == -1:
+                raise ValueError(f"Invalid short code: {code!r}")
+
+            n = n * 62 + i
+
+        try:
+            data = zlib.decompress(n.to_bytes((n.bit_length() + 7) // 8, "big"), -15)
+        except zlib.error as e:
+            raise ValueError(f"Invalid short code: {code!r}") from e
+
+        return TFSData.from_string(data)
+
+    def hex(self) -> str:
+        """The serialized protobuf as lowercase hex, for debugging."""
+        return self.to_string().hex()
+
+    def size_hint(self) -> int:
+        """Predicted size of the serialized protobuf, in bytes."""
+        return self.pb().ByteSize()
+
+    def base64_len_hint(self) -> int:
+        """Predicted length of ``as_b64()`` (padded), from ``size_hint``."""
+        return 4 * -(-self.size_hint() // 3)
 
     @staticmethod
     def from_interface(
         *,
         flight_data: List[FlightData],
-        trip: Literal["round-trip", "one-way", "multi-city"],
+        trip: Optional[Literal["round-trip", "one-way", "multi-city"]] = None,
         passengers: Passengers,
-        seat: Literal["economy", "premium-economy", "business", "first"],
+        seat: Literal["economy", "premium-economy", "business", "first", "any"],
+        allow_trip_mismatch: bool = False,
     ):
         """Use ``?tfs=`` from an interface.
 
         Args:
             flight_data (list[FlightData]): Flight data as a list.
-            trip ("one-way" | "round-trip" | "multi-city"): Trip type.
+            trip ("one-way" | "round-trip" | "multi-city", optional): Trip type.
+                Inferred from ``flight_data`` if omitted (see ``infer_trip``).
+                Must match the segments (see ``check_trip``).
             passengers (Passengers): Passengers.
-            seat ("economy" | "premium-economy" | "business" | "first"): Seat.
+            seat ("economy" | "premium-economy" | "business" | "first" | "any"): Seat.
+            allow_trip_mismatch (bool, optional): Skip ``check_trip``, here and on
+                copies (e.g. ``with_date``). Defaults to ``False``.
         """
-        trip_t = {
-            "round-trip": PB.Trip.ROUND_TRIP,
-            "one-way": PB.Trip.ONE_WAY,
-            "multi-city": PB.Trip.MULTI_CITY,
-        }[trip]
+        if trip is None:
+            trip = infer_trip(flight_data)
+
+        if not allow_trip_mismatch:
+            check_trip(trip, flight_data)
+
+        trip_t = TRIP_TYPES[trip]
         seat_t = {
             "economy": PB.Seat.ECONOMY,
             "premium-economy": PB.Seat.PREMIUM_ECONOMY,
             "business": PB.Seat.BUSINESS,
             "first": PB.Seat.FIRST,
+            "any": PB.Seat.UNKNOWN_SEAT,  # left out of the encoding
         }[seat]
 
         return TFSData(
-            flight_data=flight_data, seat=seat_t, trip=trip_t, passengers=passengers
+            flight_data=flight_data,
+            seat=seat_t,
+            trip=trip_t,
+            passengers=passengers,
+            allow_trip_mismatch=allow_trip_mismatch,
         )
 
+    def with_date(self, segment_index: int, date: str) -> "TFSData":
+        """A copy with the date of one segment replaced.
+
+        Args:
+            segment_index (int): Index of the segment in ``flight_data``.
+            date (str): The new date.
+
+        Raises:
+            ValueError: The copy no longer matches its trip type (see
+                ``check_trip``), e.g. a return date before the outbound one.
+        """
+        fd = self.flight_data[segment_index]
+        return self._with_segment(
+            segment_index,
+            FlightData(
+                date=date, from_airport=fd.from_airport, to_airport=fd.to_airport
+            ),
+        )
+
+    def reversed_segment(self, index: int) -> "TFSData":
+        """A copy with the origin and destination of one segment swapped.
+
+        On a one-way trip, this gives the return leg as its own search.
+
+        Args:
+            index (int): Index of the segment in ``flight_data``.
+
+        Raises:
+            ValueError: This is a round trip, whose segments can't be flipped one
+                at a time; use ``reverse_itinerary``.
+        """
+        if self.trip == PB.Trip.ROUND_TRIP:
+            raise ValueError(
+                "Flipping one segment breaks a round trip; use reverse_itinerary"
+            )
+
+        fd = self.flight_data[index]
+        return self._with_segment(
+            index,
+            FlightData(
+                date=fd.date, from_airport=fd.to_airport, to_airport=fd.from_airport
+            ),
+        )
+
+    def reverse_itinerary(self) -> "TFSData":
+        """A copy of a round trip flown the other way around, on the same dates.
+
+        ``A -> B`` then ``B -> A`` becomes ``B -> A`` on the outbound date, then
+        ``A -> B`` on the return date.
+
+        Raises:
+            ValueError: This is not a round trip.
+        """
+        if self.trip != PB.Trip.ROUND_TRIP:
+            raise ValueError("Only round trips can be reversed")
+
+        return self._with_flight_data(
+            [
+                FlightData(
+                    date=fd.date, from_airport=fd.to_airport, to_airport=fd.from_airport
+                )
+                for fd in self.flight_data
+            ]
+        )
+
+    def slice(self, start: int, end: int) -> "TFSData":
+        """A copy with only the segments in ``flight_data[start:end]``.
+
+        The trip type is inferred again from those segments (see ``infer_trip``).
+
+        Args:
+            start (int): Index of the first segment to keep.
+            end (int): Index after the last segment to keep.
+        """
+        flight_data = self.flight_data[start:end]
+        if not flight_data:
+            raise ValueError(f"Empty slice [{start}:{end}] of segments")
+
+        return self._with_flight_data(
+            flight_data,
+            trip=TRIP_TYPES[infer_trip(flight_data)],
+        )
+
+    def _with_segment(self, index: int, fd: FlightData) -> "TFSData":
+        flight_data = list(self.flight_data)
+        flight_data[index] = fd
+        return self._with_flight_data(flight_data)
+
+    def _with_flight_data(
+        self,
+        flight_data: List[FlightData],
+        trip: Optional[PB.Trip] = None,  # type: ignore
+    ) -> "TFSData":
+        tfs = TFSData(
+            flight_data=flight_data,
+            seat=self.seat,
+            trip=self.trip if trip is None else trip,
+            passengers=self.passengers,
+            hl=self.hl,
+            curr=self.curr,
+            allow_trip_mismatch=self.allow_trip_mismatch,
+        )
+
+        # Copies must be as valid as anything `create_filter` builds
+        if not self.allow_trip_mismatch:
+            check_trip(tfs._trip_name(), flight_data)  # type: ignore
+
+        return tfs
+
+    def diff(self, other: "TFSData") -> Dict[str, Any]:
+        """Fields that differ from ``other``, as ``{field: (self's, other's)}``.
+
+        Segments are compared one by one and reported under ``"segments"`` as
+        ``{index: (self's, other's)}``, with ``None`` for a missing segment.
+        """
+        d: Dict[str, Any] = {}
+
+        if self.seat != other.seat:
+            d["seat"] = (PB.Seat.Name(self.seat), PB.Seat.Name(other.seat))
+
+        if self.trip != other.trip:
+            d["trip"] = (PB.Trip.Name(self.trip), PB.Trip.Name(other.trip))
+
+        if self.passengers._data != other.passengers._data:
+            d["passengers"] = (self.passengers, other.passengers)
+
+        segments = {}
+        for i in range(max(len(self.flight_data), len(other.flight_data))):
+            a = self.flight_data[i] if i < len(self.flight_data) else None
+            b = other.flight_data[i] if i < len(other.flight_data) else None
+            if a != b:
+                segments[i] = (a, b)
+
+        if segments:
+            d["segments"] = segments
+
+        return d
+
+    def __iter__(self) -> Iterator[Dict[str, str]]:
+        return (fd.as_dict() for fd in self.flight_data)
+
+    def __getitem__(
+        self, index: Union[int, slice]
+    ) -> Union[Dict[str, str], List[Dict[str, str]]]:
+        if isinstance(index, slice):
+            return [fd.as_dict() for fd in self.flight_data[index]]
+
+        try:
+            return self.flight_data[index].as_dict()
+        except IndexError:
+            raise IndexError(
+                f"segment index {index} out of range ({len(self.flight_data)} segments)"
+            ) from None
+
+    def dump(self) -> str:
+        """A readable summary followed by the base64 data, for bug reports."""
+        return f"{self}\ntfs: {self.as_b64().decode()}"
+
+    def _trip_name(self) -> str:
+        names = {trip: name for name, trip in TRIP_TYPES.items()}
+        return names.get(self.trip, "unknown-trip")
+
+    def _seat_name(self) -> str:
+        if self.seat == PB.Seat.UNKNOWN_SEAT:
+            return "any"
+
+        return PB.Seat.Name(self.seat).lower().replace("_", "-")
+
+    def proto_debug(self) -> str:
+        """The protobuf message in text format, without the serialized bytes."""
+        return str(self.pb())
+
+    def __str__(self) -> str:
+        trip = self._trip_name()
+        seat = self._seat_name()
+        passengers = ", ".join(f"{k}={v}" for k, v in self.passenger_counts().items())
+
+        lines = [f"{trip}, {seat}"]
+        lines += [
+            f"  {fd.date}  {fd.from_airport} -> {fd.to_airport}"
+            for fd in self.flight_data
+        ]
+        lines.append(f"  passengers: {passengers}")
+        return "\n".join(lines)
+
     def __repr__(self) -> str:
-        return f"TFSData({'hello'!r}, flight_data={self.flight_data!r})"
+        return (
+            f"TFSData(trip={self._trip_name()!r}, seat={self._seat_name()!r}, "
+            f"flight_data={self.flight_data!r}, passengers={self.passengers!r})"
+        )
+
+
+def fan_out(
+    base: TFSData,
+    field: Literal["date", "from", "to"],
+    values: List[str],
+    segment_index: int = 0,
+) -> List[TFSData]:
+    """Copies of ``base``, one per value, with a single segment field varied.
+
+    Args:
+        base (TFSData): The search to copy.
+        field ("date" | "from" | "to"): The segment field to vary.
+        values (list[str]): Values for the field, e.g. dates or airport codes.
+        segment_index (int, optional): Index of the segment to vary. Defaults to 0.
+
+    Raises:
+        ValueError: A copy no longer matches its trip type (see ``check_trip``),
+            e.g. varying ``"to"`` on one leg of a round trip.
+    """
+    if field not in ("date", "from", "to"):
+        raise ValueError(f"Cannot fan out over {field!r}, use 'date', 'from' or 'to'")
+
+    results = []
+    for value in values:
+        segment = base.flight_data[segment_index].as_dict()
+        segment[field] = value
+        results.append(
+            base._with_segment(
+                segment_index,
+                FlightData(
+                    date=segment["date"],
+                    from_airport=segment["from"],
+                    to_airport=segment["to"],
+                ),
+            )
+        )
+
+    return results
+
+
+def summarize_tfs(data: Union[str, bytes]) -> str:
+    """Summarize base64 ``?tfs=`` data as readable text, e.g. for support tickets.
+
+    Args:
+        data (str | bytes): The base64 data, standard or URL-safe (e.g. the
+            ``tfs`` param of a browser URL).
+
+    Raises:
+        ValueError: The data could not be decoded.
+    """
+    return str(_decode_b64(data))
+
+
+def tfs_equal(a: Union[str, bytes], b: Union[str, bytes]) -> bool:
+    """Whether two base64 ``?tfs=`` strings describe the same search.
+
+    Passenger order, the exact byte layout and the base64 variant (standard or
+    URL-safe) are ignored.
+
+    Args:
+        a (str | bytes): The first base64 data.
+        b (str | bytes): The second base64 data.
+
+    Raises:
+        ValueError: Either one could not be decoded.
+    """
+    return not _decode_b64(a).diff(_decode_b64(b))
+
+
+def tfs_schema() -> Dict[str, Any]:
+    """Describe the ``?tfs=`` protobuf schema, for tooling.
+
+    Returns:
+        dict: ``{field_name: {"tag": ..., "type": ..., "repeated": ...}}`` for each
+            field of the top-level message. Message fields have their own fields
+            described under ``"fields"``.
+    """
+    return _describe(PB.Info.DESCRIPTOR)
+
+
+def _describe(descriptor: Any) -> Dict[str, Any]:
+    fields = {}
+    for field in descriptor.fields:
+        if field.message_type is not None:
+            type_name = field.message_type.name
+        elif field.enum_type is not None:
+            type_name = field.enum_type.name
+        else:
+            type_name = "string"  # the only scalar type in flights.proto
+
+        fields[field.name] = {
+            "tag": field.number,
+            "type": type_name,
+            "repeated": field.label == field.LABEL_REPEATED,
+        }
+        if field.message_type is not None:
+            fields[field.name]["fields"] = _describe(field.message_type)
+
+    return fields
+
+
+def write_jsonl(path: str, tfs_list: Iterable[TFSData]) -> None:
+    """Write searches to a JSON Lines file, one ``TFSData.as_dict()`` per line.
+
+    Searches are written as they come, so ``tfs_list`` can be a generator.
+
+    Args:
+        path (str): The file to write.
+        tfs_list (Iterable[TFSData]): The searches.
+    """
+    with open(path, "w", encoding="utf-8") as f:
+        for tfs in tfs_list:
+            f.write(json.dumps(tfs.as_dict()) + "\n")
+
+
+def read_jsonl(path: str) -> List[TFSData]:
+    """Read searches written by ``write_jsonl``.
+
+    Each line's ``"tfs"`` is decoded, with ``"hl"`` and ``"curr"`` kept if set.
+    Blank lines are skipped.
+
+    Args:
+        path (str): The file to read.
+
+    Raises:
+        ValueError: A line could not be parsed; the message has its line number.
+    """
+    results = []
+    with open(path, "r", encoding="utf-8") as f:
+        for lineno, line in enumerate(f, 1):
+            if not line.strip():
+                continue
+
+            try:
+                obj = json.loads(line)
+                data = obj["tfs"]
+                if not isinstance(data, str):
+                    raise TypeError(f"'tfs' is not a string: {data!r}")
+
+                tfs = _decode_b64(data)
+            except KeyError:
+                raise ValueError(f"{path}:{lineno}: missing 'tfs'") from None
+            except (ValueError, TypeError) as e:
+                raise ValueError(f"{path}:{lineno}: invalid search: {e}") from e
+
+            tfs.hl = obj.get("hl")
+            tfs.curr = obj.get("curr")
+            results.append(tfs)
+
+    return results
+
+
+def _decode_b64(data: Union[str, bytes]) -> TFSData:
+    try:
+        tfs = TFSData.from_b64(data)
+    except (ValueError, DecodeError) as e:
+        raise ValueError(f"Invalid tfs data: {data!r}") from e
+
+    # Junk can parse as an empty message, but a search has at least one segment
+    if not tfs.flight_data:
+        raise ValueError(f"Invalid tfs data, no segments: {data!r}")
+
+    return tfs
@@ -0,0 +1,100 @@
+import unittest
+
+from fast_flights import flights_pb2 as PB
+from fast_flights import FlightData, Passengers, create_filter, fan_out
+
+
+class CopiesTest(unittest.TestCase):
+    def setUp(self):
+        self.round_trip = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF"),
+                FlightData(date="2025-05-17", from_airport="CNF", to_airport="GRU"),
+            ],
+            trip="round-trip",
+            seat="economy",
+            passengers=Passengers(adults=1),
+        )
+
+    def test_indexing(self):
+        self.assertEqual(self.round_trip[0], list(self.round_trip)[0])
+        self.assertEqual(self.round_trip[-1]["from"], "CNF")
+        self.assertEqual(self.round_trip[0:2], list(self.round_trip))
+
+        with self.assertRaises(IndexError):
+            self.round_trip[2]
+
+    def test_with_date(self):
+        copy = self.round_trip.with_date(1, "2025-05-20")
+
+        self.assertEqual(copy.flight_data[1].date, "2025-05-20")
+        self.assertEqual(self.round_trip.flight_data[1].date, "2025-05-17")
+        self.assertEqual(copy.diff(self.round_trip).keys(), {"segments"})
+
+    def test_with_date_keeps_round_trips_valid(self):
+        with self.assertRaises(ValueError):
+            self.round_trip.with_date(1, "2025-05-01")
+
+    def test_reversed_segment_on_round_trip_is_rejected(self):
+        with self.assertRaises(ValueError):
+            self.round_trip.reversed_segment(0)
+
+    def test_reverse_itinerary(self):
+        reverse = self.round_trip.reverse_itinerary()
+
+        self.assertEqual(
+            [fd.as_dict() for fd in reverse.flight_data],
+            [
+                {"date": "2025-05-10", "from": "CNF", "to": "GRU"},
+                {"date": "2025-05-17", "from": "GRU", "to": "CNF"},
+            ],
+        )
+        self.assertTrue(reverse.is_valid_round_trip())
+
+    def test_fan_out(self):
+        copies = fan_out(self.round_trip, "date", ["2025-05-11", "2025-05-12"])
+        self.assertEqual(
+            [tfs.flight_data[0].date for tfs in copies], ["2025-05-11", "2025-05-12"]
+        )
+
+    def test_fan_out_keeps_round_trips_valid(self):
+        with self.assertRaises(ValueError):
+            fan_out(self.round_trip, "to", ["SDU"])
+
+    def test_slice_infers_trip(self):
+        tfs = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF"),
+                FlightData(date="2025-05-12", from_airport="CNF", to_airport="SDU"),
+                FlightData(date="2025-05-14", from_airport="SDU", to_airport="POA"),
+                FlightData(date="2025-05-17", from_airport="POA", to_airport="GRU"),
+            ],
+            trip="multi-city",
+            seat="economy",
+            passengers=Passengers(adults=1),
+        )
+
+        self.assertEqual(tfs.slice(0, 2).trip, PB.Trip.MULTI_CITY)
+        self.assertEqual(tfs.slice(0, 2)[:], tfs[0:2])
+        self.assertEqual(tfs.slice(0, 1).trip, PB.Trip.ONE_WAY)
+        self.assertEqual(self.round_trip.slice(0, 2).trip, PB.Trip.ROUND_TRIP)
+
+    def test_allow_trip_mismatch_is_kept(self):
+        tfs = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF"),
+                FlightData(date="2025-05-12", from_airport="CNF", to_airport="SDU"),
+            ],
+            trip="one-way",
+            seat="economy",
+            passengers=Passengers(adults=1),
+            allow_trip_mismatch=True,
+        )
+
+        copy = tfs.with_date(0, "2025-05-11")
+        self.assertEqual(copy.flight_data[0].date, "2025-05-11")
+        self.assertTrue(copy.allow_trip_mismatch)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,32 @@
+import unittest
+from urllib.parse import quote_plus
+
+from fast_flights import FlightData, Passengers, create_filter
+
+
+class CurlCommandTest(unittest.TestCase):
+    def setUp(self):
+        self.tfs = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF")
+            ],
+            trip="one-way",
+            seat="economy",
+            passengers=Passengers(adults=1),
+        )
+
+    def test_matches_get_flights(self):
+        command = self.tfs.curl_command(curr="USD")
+
+        self.assertTrue(command.startswith("curl "))
+        self.assertIn("tfu=EgQIABABIgA", command)
+        self.assertIn("curr=USD", command)
+        self.assertIn("-H 'user-agent: Mozilla/5.0", command)
+
+    def test_contains_tfs(self):
+        tfs = quote_plus(self.tfs.as_b64().decode())
+        self.assertIn(f"tfs={tfs}", self.tfs.curl_command())
+
+
+if __name__ == "__main__":
+    unittest.main()
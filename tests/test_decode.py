@@ -0,0 +1,101 @@
+import unittest
+from urllib.parse import urlencode
+
+from fast_flights import flights_pb2 as PB
+from fast_flights import FlightData, Passengers, TFSData, create_filter, summarize_tfs
+
+
+class DecodeTest(unittest.TestCase):
+    def setUp(self):
+        self.tfs = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF")
+            ],
+            trip="one-way",
+            seat="economy",
+            passengers=Passengers(adults=1),
+        )
+
+    def test_lowercase_codes_are_uppercased(self):
+        lower = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="gru", to_airport="cnf")
+            ],
+            trip="one-way",
+            seat="economy",
+            passengers=Passengers(adults=1),
+        )
+        self.assertEqual(lower.to_string(), self.tfs.to_string())
+
+    def test_city_ids_survive_decoding(self):
+        info = self.tfs.pb()
+        info.data[0].to_flight.airport = "/m/02_286"
+        raw = info.SerializeToString()
+
+        tfs = TFSData.from_string(raw)
+        self.assertEqual(tfs.flight_data[0].to_airport, "/m/02_286")
+        self.assertEqual(tfs.to_string(), raw)
+
+    def test_built_searches_are_self_consistent(self):
+        self.assertTrue(self.tfs.self_consistent())
+        self.assertTrue(TFSData.from_string(self.tfs.to_string()).self_consistent())
+
+    def test_drift_is_caught(self):
+        info = self.tfs.pb()
+        info.passengers.extend([PB.Passenger.CHILD, PB.Passenger.ADULT])
+        raw = info.SerializeToString()
+
+        self.assertFalse(TFSData.from_string(raw).self_consistent())
+
+    def test_b64_variants(self):
+        standard = self.tfs.as_b64("standard")
+        urlsafe = self.tfs.as_b64("urlsafe")
+        nopad = self.tfs.as_b64("urlsafe_nopad")
+
+        self.assertEqual(standard, self.tfs.as_b64())
+        self.assertEqual(urlsafe, standard.replace(b"+", b"-").replace(b"/", b"_"))
+        self.assertEqual(nopad, urlsafe.rstrip(b"="))
+        self.assertFalse(set(urlsafe) & set(b"+/"))
+        self.assertNotIn(b"=", nopad)
+
+        for data in (standard, urlsafe, nopad):
+            self.assertEqual(TFSData.from_b64(data).to_string(), self.tfs.to_string())
+
+        with self.assertRaises(ValueError):
+            self.tfs.as_b64("base32")  # type: ignore
+
+    def test_from_url(self):
+        tfs = TFSData.from_url(
+            "https://www.google.com/travel/flights?"
+            + urlencode(
+                {"tfs": self.tfs.as_b64("urlsafe_nopad"), "hl": "pt-BR", "curr": "BRL"}
+            )
+        )
+
+        self.assertEqual(tfs.to_string(), self.tfs.to_string())
+        self.assertEqual((tfs.hl, tfs.curr), ("pt-BR", "BRL"))
+
+    def test_from_url_without_tfs(self):
+        with self.assertRaises(ValueError):
+            TFSData.from_url("https://www.google.com/travel/flights?hl=en")
+
+    def test_non_base64_is_rejected(self):
+        with self.assertRaises(ValueError):
+            summarize_tfs("!!!")
+
+        with self.assertRaises(ValueError):
+            TFSData.from_url("https://www.google.com/travel/flights?tfs=%25%25%25")
+
+    def test_empty_search_is_rejected(self):
+        empty = TFSData(
+            flight_data=[],
+            seat=self.tfs.seat,
+            trip=self.tfs.trip,
+            passengers=Passengers(),
+        )
+        with self.assertRaises(ValueError):
+            summarize_tfs(empty.as_b64())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,58 @@
+import os
+import tempfile
+import unittest
+
+from fast_flights import (
+    FlightData,
+    Passengers,
+    create_filter,
+    read_jsonl,
+    write_jsonl,
+)
+
+
+class JsonlTest(unittest.TestCase):
+    def setUp(self):
+        fd, self.path = tempfile.mkstemp(suffix=".jsonl")
+        os.close(fd)
+
+    def tearDown(self):
+        os.remove(self.path)
+
+    def read(self, *lines):
+        with open(self.path, "w", encoding="utf-8") as f:
+            f.write("\n".join(lines) + "\n")
+
+        return read_jsonl(self.path)
+
+    def test_round_trip(self):
+        searches = [
+            create_filter(
+                flight_data=[
+                    FlightData(date="2025-05-10", from_airport="GRU", to_airport=to)
+                ],
+                seat=seat,
+                passengers=Passengers(adults=2, children=1),
+            )
+            for to, seat in (("CNF", "economy"), ("SDU", "any"), ("POA", "first"))
+        ]
+        searches[0].hl, searches[0].curr = "pt-BR", "BRL"
+
+        write_jsonl(self.path, iter(searches))
+        loaded = read_jsonl(self.path)
+
+        self.assertEqual(len(loaded), len(searches))
+        for a, b in zip(searches, loaded):
+            self.assertEqual(a.to_string(), b.to_string())
+            self.assertEqual((a.hl, a.curr), (b.hl, b.curr))
+            self.assertEqual(a.as_dict(), b.as_dict())
+
+    def test_errors_have_line_numbers(self):
+        for line in ('{"tfs": 5}', '{"tfs": "!!"}', '{"hl": "en"}', "not json"):
+            with self.subTest(line=line):
+                with self.assertRaisesRegex(ValueError, r":2: "):
+                    self.read("", line)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,35 @@
+import unittest
+
+from fast_flights import flights_pb2 as PB
+from fast_flights import Passengers
+
+
+class SeatLimitTest(unittest.TestCase):
+    def test_lap_infants_dont_count(self):
+        Passengers(adults=2, children=7, infants_on_lap=2)
+
+    def test_infants_in_seat_count(self):
+        with self.assertRaises(AssertionError):
+            Passengers(adults=2, children=7, infants_in_seat=1)
+
+
+class InfantLimitTest(unittest.TestCase):
+    def test_two_infants_per_adult(self):
+        Passengers(adults=1, infants_in_seat=1, infants_on_lap=1)
+        Passengers(adults=1, infants_in_seat=2)
+
+    def test_too_many_infants(self):
+        with self.assertRaises(AssertionError):
+            Passengers(adults=1, infants_in_seat=2, infants_on_lap=1)
+
+    def test_one_lap_infant_per_adult(self):
+        with self.assertRaises(AssertionError):
+            Passengers(adults=1, infants_on_lap=2)
+
+    def test_decoded_passengers_skip_limits(self):
+        passengers = [PB.Passenger.ADULT] + [PB.Passenger.INFANT_ON_LAP] * 2
+        self.assertEqual(Passengers.from_pb(passengers)._data, (1, 0, 0, 2))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,71 @@
+import unittest
+import zlib
+
+from fast_flights import FlightData, Passengers, TFSData, create_filter
+from fast_flights.flights_impl import BASE62
+
+
+def deflate(data):
+    z = zlib.compressobj(9, zlib.DEFLATED, -15)
+    return z.compress(data) + z.flush()
+
+
+class ShortCodeTest(unittest.TestCase):
+    def setUp(self):
+        legs = [
+            ("GRU", "CNF", "2025-05-10"),
+            ("CNF", "SDU", "2025-05-12"),
+            ("SDU", "POA", "2025-05-14"),
+            ("POA", "GRU", "2025-05-17"),
+        ]
+        flight_data = [
+            FlightData(date=date, from_airport=from_airport, to_airport=to_airport)
+            for from_airport, to_airport, date in legs
+        ]
+
+        self.searches = [
+            create_filter(
+                flight_data=flight_data[:1],
+                seat="economy",
+                passengers=Passengers(adults=1),
+            ),
+            create_filter(
+                flight_data=[
+                    flight_data[0],
+                    FlightData(date="2025-05-17", from_airport="CNF", to_airport="GRU"),
+                ],
+                seat="any",
+                passengers=Passengers(adults=2, infants_on_lap=1),
+            ),
+            create_filter(
+                flight_data=flight_data,
+                seat="business",
+                passengers=Passengers(adults=1, children=2),
+            ),
+        ]
+
+    def test_round_trip(self):
+        for tfs in self.searches:
+            with self.subTest(tfs=tfs):
+                code = tfs.short_code()
+
+                self.assertTrue(all(c in BASE62 for c in code))
+                self.assertEqual(
+                    TFSData.from_short_code(code).to_string(), tfs.to_string()
+                )
+
+    def test_no_leading_zero_byte(self):
+        # A leading zero byte would be lost in the integer the code encodes
+        for tfs in self.searches:
+            with self.subTest(tfs=tfs):
+                self.assertNotEqual(deflate(tfs.to_string())[0], 0)
+
+    def test_invalid_codes_are_rejected(self):
+        for code in ("", "not-base62", "abc"):
+            with self.subTest(code=code):
+                with self.assertRaises(ValueError):
+                    TFSData.from_short_code(code)
+
+
+if __name__ == "__main__":
+    unittest.main()
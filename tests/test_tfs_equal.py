@@ -0,0 +1,39 @@
+import base64
+import unittest
+
+from fast_flights import FlightData, Passengers, create_filter, tfs_equal
+
+
+class TfsEqualTest(unittest.TestCase):
+    def setUp(self):
+        self.tfs = create_filter(
+            flight_data=[
+                FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF")
+            ],
+            trip="one-way",
+            seat="economy",
+            passengers=Passengers(adults=2, children=1, infants_on_lap=1),
+        )
+
+    def test_passenger_order_is_ignored(self):
+        info = self.tfs.pb()
+        passengers = list(info.passengers)[::-1]
+        del info.passengers[:]
+        info.passengers.extend(passengers)
+        reordered = base64.b64encode(info.SerializeToString())
+
+        self.assertNotEqual(reordered, self.tfs.as_b64())
+        self.assertTrue(tfs_equal(self.tfs.as_b64(), reordered))
+
+    def test_browser_form_is_accepted(self):
+        self.assertTrue(
+            tfs_equal(self.tfs.as_b64("urlsafe_nopad"), self.tfs.as_b64())
+        )
+
+    def test_different_routes_differ(self):
+        other = self.tfs.reversed_segment(0)
+        self.assertFalse(tfs_equal(self.tfs.as_b64(), other.as_b64()))
+
+
+if __name__ == "__main__":
+    unittest.main()
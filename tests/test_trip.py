@@ -0,0 +1,83 @@
+import unittest
+
+from fast_flights import flights_pb2 as PB
+from fast_flights import FlightData, Passengers, create_filter
+from fast_flights.flights_impl import infer_trip
+
+GRU_CNF = FlightData(date="2025-05-10", from_airport="GRU", to_airport="CNF")
+CNF_GRU = FlightData(date="2025-05-17", from_airport="CNF", to_airport="GRU")
+CNF_SDU = FlightData(date="2025-05-12", from_airport="CNF", to_airport="SDU")
+
+
+def make(flight_data, **kwargs):
+    return create_filter(
+        flight_data=flight_data,
+        seat="economy",
+        passengers=Passengers(adults=1),
+        **kwargs,
+    )
+
+
+class CheckTripTest(unittest.TestCase):
+    def test_one_way_with_several_legs(self):
+        with self.assertRaisesRegex(ValueError, "multi-city"):
+            make([GRU_CNF, CNF_SDU], trip="one-way")
+
+    def test_round_trip_with_several_legs(self):
+        with self.assertRaisesRegex(ValueError, "exactly two segments"):
+            make([GRU_CNF, CNF_GRU, GRU_CNF], trip="round-trip")
+
+    def test_multi_city_with_one_leg(self):
+        with self.assertRaisesRegex(ValueError, "one-way"):
+            make([GRU_CNF], trip="multi-city")
+
+    def test_override(self):
+        tfs = make([GRU_CNF, CNF_SDU], trip="one-way", allow_trip_mismatch=True)
+        self.assertEqual(len(tfs.flight_data), 2)
+
+    def test_round_trip_without_return(self):
+        with self.assertRaisesRegex(ValueError, "return segment"):
+            make([GRU_CNF], trip="round-trip")
+
+    def test_open_jaw(self):
+        with self.assertRaisesRegex(ValueError, "Open-jaw"):
+            make(
+                [
+                    GRU_CNF,
+                    FlightData(date="2025-05-17", from_airport="SDU", to_airport="GRU"),
+                ],
+                trip="round-trip",
+            )
+
+
+class InferTripTest(unittest.TestCase):
+    def test_one_segment(self):
+        self.assertEqual(infer_trip([GRU_CNF]), "one-way")
+
+    def test_mirrored_segments(self):
+        self.assertEqual(infer_trip([GRU_CNF, CNF_GRU]), "round-trip")
+
+    def test_return_before_outbound(self):
+        self.assertEqual(infer_trip([CNF_GRU, GRU_CNF]), "multi-city")
+
+    def test_several_legs(self):
+        self.assertEqual(infer_trip([GRU_CNF, CNF_SDU]), "multi-city")
+
+    def test_trip_can_be_omitted(self):
+        self.assertEqual(make([GRU_CNF, CNF_GRU]).trip, PB.Trip.ROUND_TRIP)
+
+
+class ChronologicalTest(unittest.TestCase):
+    def test_ordered(self):
+        make([GRU_CNF, CNF_SDU], check_chronological=True)
+
+    def test_unordered(self):
+        with self.assertRaisesRegex(ValueError, "chronological"):
+            make([CNF_SDU, GRU_CNF], check_chronological=True)
+
+    def test_off_by_default(self):
+        make([CNF_SDU, GRU_CNF])
+
+
+if __name__ == "__main__":
+    unittest.main()